@@ -0,0 +1,364 @@
+//! Proc-macro backing dynenum's static mode.
+//!
+//! The [`dynenum!`] macro expands a declarative spec into the same `enum`,
+//! `Display`, `FromStr`, `AsRef<str>`, and `ALL_*` const that the old
+//! `build.rs` used to write into the source tree, plus serde
+//! `Serialize`/`Deserialize` impls using the canonical string form. Unlike the file-based
+//! codegen, it expands inline, so a single crate may declare any number of
+//! enums and nothing is written back into `src/`.
+//!
+//! Variants loaded from YAML may optionally carry a `regex` and/or a list of
+//! `keywords`; from those the macro also emits `matches`/`classify`, so the
+//! enum doubles as a classifier.
+//!
+//! A top-level `__case` option (`snake`, `kebab`, `camel`, `pascal`) controls
+//! the canonical string casing, and `from_str_ignore_case` accepts input in any
+//! casing without changing the exact-match behavior of `FromStr`.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+
+use convert_case::{Case, Casing};
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::{braced, parse_macro_input, Ident, LitStr, Token};
+
+/// A single variant and its optional classification patterns.
+struct Variant {
+    name: String,
+    regex: Option<String>,
+    keywords: Vec<String>,
+}
+
+impl Variant {
+    /// A bare variant with no patterns attached (the inline-spec case).
+    fn bare(name: String) -> Self {
+        Variant {
+            name,
+            regex: None,
+            keywords: Vec::new(),
+        }
+    }
+}
+
+/// The case style applied to the canonical `Display`/`AsRef<str>` string.
+///
+/// `Exact` (the default) keeps the YAML spelling verbatim; the others route
+/// each variant name through `convert_case` so the emitted strings share a
+/// consistent casing regardless of how they were written.
+#[derive(Clone, Copy)]
+enum CaseStyle {
+    Exact,
+    Snake,
+    Kebab,
+    Camel,
+    Pascal,
+}
+
+impl CaseStyle {
+    /// Parses the `__case` option value, rejecting unknown styles.
+    fn parse(value: &str) -> Result<Self, String> {
+        match value {
+            "snake" => Ok(CaseStyle::Snake),
+            "kebab" => Ok(CaseStyle::Kebab),
+            "camel" => Ok(CaseStyle::Camel),
+            "pascal" => Ok(CaseStyle::Pascal),
+            other => Err(format!("unknown __case style {other:?}")),
+        }
+    }
+
+    /// Renders `name` in this style, leaving it untouched for `Exact`.
+    fn apply(self, name: &str) -> String {
+        match self {
+            CaseStyle::Exact => name.to_string(),
+            CaseStyle::Snake => name.to_case(Case::Snake),
+            CaseStyle::Kebab => name.to_case(Case::Kebab),
+            CaseStyle::Camel => name.to_case(Case::Camel),
+            CaseStyle::Pascal => name.to_case(Case::Pascal),
+        }
+    }
+}
+
+/// Either an inline spec (`Information { email, phone_number }`) or a
+/// reference to a YAML file (`include_yaml = "types.yaml"`).
+enum DynEnum {
+    Inline { name: String, variants: Vec<Variant> },
+    Yaml { path: LitStr },
+}
+
+impl Parse for DynEnum {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let head: Ident = input.parse()?;
+        if head == "include_yaml" {
+            input.parse::<Token![=]>()?;
+            let path: LitStr = input.parse()?;
+            Ok(DynEnum::Yaml { path })
+        } else {
+            let content;
+            braced!(content in input);
+            let variants = content.parse_terminated(Ident::parse, Token![,])?;
+            Ok(DynEnum::Inline {
+                name: head.to_string(),
+                variants: variants
+                    .into_iter()
+                    .map(|v| Variant::bare(v.to_string()))
+                    .collect(),
+            })
+        }
+    }
+}
+
+/// Generates a classification-type enum from a declarative spec.
+///
+/// # Examples
+/// ```ignore
+/// dynenum! { Information { email, phone_number, credential, date } }
+/// // or, reading the variant list from a YAML file at the crate root:
+/// dynenum!(include_yaml = "types.yaml");
+/// ```
+///
+/// # Required dependencies
+/// The expansion refers to items by absolute path, so the invoking crate must
+/// have the following dependencies in scope:
+/// * `convert_case` — always (used by `from_str_ignore_case`);
+/// * `serde` — always (the `Serialize`/`Deserialize` impls);
+/// * `regex` — only when any variant carries a `regex` pattern;
+/// * a crate reachable as `::dynenum` that exports `ClassificationType` — the
+///   `dynenum` crate provides the trait. It does not re-export this macro or
+///   the crates above, so each of them must be a direct dependency of the
+///   invoking crate.
+#[proc_macro]
+pub fn dynenum(input: TokenStream) -> TokenStream {
+    let parsed = parse_macro_input!(input as DynEnum);
+    let (name, variants, case) = match parsed {
+        DynEnum::Inline { name, variants } => (name, variants, CaseStyle::Exact),
+        DynEnum::Yaml { path } => match load_yaml(&path.value()) {
+            Ok(spec) => spec,
+            Err(e) => return syn::Error::new(path.span(), e).to_compile_error().into(),
+        },
+    };
+    expand(&name, &variants, case).into()
+}
+
+/// Reads the enum name and variant list from a YAML document, resolving `path`
+/// against `CARGO_MANIFEST_DIR`.
+///
+/// The first top-level key that is not an option (see below) names the enum and
+/// maps to its sequence of variants. Each sequence entry is either a scalar
+/// (the variant name) or a single-key mapping
+/// `name: {regex: "...", keywords: [...]}`. An optional top-level `__case`
+/// option (`snake`, `kebab`, `camel`, or `pascal`) sets the canonical string
+/// style; without it the YAML spelling is kept verbatim.
+fn load_yaml(path: &str) -> Result<(String, Vec<Variant>, CaseStyle), String> {
+    let base = env::var("CARGO_MANIFEST_DIR").map_err(|_| "CARGO_MANIFEST_DIR is not set".to_string())?;
+    let full = Path::new(&base).join(path);
+    let yaml = fs::read_to_string(&full).map_err(|e| format!("failed to read {}: {e}", full.display()))?;
+    let doc: serde_yaml::Value = serde_yaml::from_str(&yaml).map_err(|e| format!("invalid YAML: {e}"))?;
+    let map = doc
+        .as_mapping()
+        .ok_or_else(|| "YAML must map an enum name to a sequence of variants".to_string())?;
+    let case = match map.get("__case") {
+        Some(v) => CaseStyle::parse(v.as_str().ok_or_else(|| "__case must be a string".to_string())?)?,
+        None => CaseStyle::Exact,
+    };
+    let (name, seq) = map
+        .iter()
+        .filter(|(k, _)| k.as_str().map(|s| !s.starts_with("__")).unwrap_or(true))
+        .find_map(|(k, v)| Some((k.as_str()?.to_string(), v.as_sequence()?)))
+        .ok_or_else(|| "YAML must have a key (enum name) mapping to a sequence of variants".to_string())?;
+    let variants = seq
+        .iter()
+        .map(parse_variant)
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok((name, variants, case))
+}
+
+/// Parses one sequence entry into a [`Variant`].
+fn parse_variant(entry: &serde_yaml::Value) -> Result<Variant, String> {
+    if let Some(name) = entry.as_str() {
+        return Ok(Variant::bare(name.to_string()));
+    }
+    let (key, props) = entry
+        .as_mapping()
+        .and_then(|m| m.iter().next())
+        .ok_or_else(|| "variant must be a string or a single-key mapping".to_string())?;
+    let name = key
+        .as_str()
+        .ok_or_else(|| "variant name must be a string".to_string())?
+        .to_string();
+    let regex = props
+        .get("regex")
+        .and_then(|r| r.as_str())
+        .map(str::to_string);
+    let keywords = props
+        .get("keywords")
+        .and_then(|k| k.as_sequence())
+        .map(|seq| seq.iter().filter_map(|k| k.as_str().map(str::to_string)).collect())
+        .unwrap_or_default();
+    Ok(Variant {
+        name,
+        regex,
+        keywords,
+    })
+}
+
+/// Emits the enum definition and its trait impls.
+fn expand(enum_name: &str, variants: &[Variant], case: CaseStyle) -> proc_macro2::TokenStream {
+    let enum_ident = Ident::new(&enum_name.to_case(Case::Pascal), Span::call_site());
+    let const_ident = Ident::new(
+        &format!("ALL_{}", enum_name.to_case(Case::UpperSnake)),
+        Span::call_site(),
+    );
+    let variant_idents: Vec<Ident> = variants
+        .iter()
+        .map(|v| Ident::new(&v.name.to_case(Case::Pascal), Span::call_site()))
+        .collect();
+    let strings: Vec<String> = variants.iter().map(|v| case.apply(&v.name)).collect();
+    let is_idents: Vec<Ident> = variants
+        .iter()
+        .map(|v| Ident::new(&format!("is_{}", v.name.to_case(Case::Snake)), Span::call_site()))
+        .collect();
+    let match_bodies: Vec<proc_macro2::TokenStream> = variants.iter().map(variant_matcher).collect();
+    let count = variants.len();
+
+    quote! {
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum #enum_ident {
+            #( #variant_idents, )*
+        }
+
+        impl #enum_ident {
+            /// The number of variants.
+            pub const COUNT: usize = #count;
+
+            /// Iterates over every variant in declaration order.
+            pub fn iter() -> impl ::std::iter::Iterator<Item = Self> {
+                [ #( #enum_ident::#variant_idents, )* ].into_iter()
+            }
+
+            // Only `is_*` predicates are generated: the variants are field-less,
+            // so strum-style `try_into`/`as_*` accessors would have no payload to
+            // hand back and collapse to the `is_*`/`==` checks already provided.
+            #(
+            /// Returns `true` if this value is the corresponding variant.
+            pub fn #is_idents(&self) -> bool {
+                matches!(self, #enum_ident::#variant_idents)
+            }
+            )*
+
+            /// Returns `true` if `input` matches this variant's regex or any of
+            /// its keywords. Variants without patterns never match.
+            pub fn matches(&self, input: &str) -> bool {
+                match self {
+                    #( #enum_ident::#variant_idents => #match_bodies, )*
+                }
+            }
+
+            /// Classifies `input` by trying each variant in declaration order
+            /// and returning the first whose pattern matches, or `None`.
+            ///
+            /// This is an associated function rather than a free item so that
+            /// several `dynenum!` enums can coexist in one crate.
+            pub fn classify(input: &str) -> ::std::option::Option<Self> {
+                Self::iter().find(|v| v.matches(input))
+            }
+        }
+
+        impl ::std::fmt::Display for #enum_ident {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                let s = match self {
+                    #( #enum_ident::#variant_idents => #strings, )*
+                };
+                write!(f, "{}", s)
+            }
+        }
+
+        impl ::std::str::FromStr for #enum_ident {
+            type Err = &'static str;
+            fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+                match s {
+                    #( #strings => Ok(#enum_ident::#variant_idents), )*
+                    _ => Err("Unknown type"),
+                }
+            }
+        }
+
+        impl #enum_ident {
+            /// Parses `s` ignoring its casing by normalizing both the input and
+            /// each canonical string through `convert_case` before comparing.
+            /// This accepts e.g. `"Email"` or `"PHONE_NUMBER"` where
+            /// [`FromStr`](::std::str::FromStr) requires the exact spelling.
+            pub fn from_str_ignore_case(s: &str) -> ::std::result::Result<Self, &'static str> {
+                use ::convert_case::Casing;
+                let want = s.to_case(::convert_case::Case::Snake);
+                #enum_ident::iter()
+                    .find(|v| ::std::convert::AsRef::<str>::as_ref(v).to_case(::convert_case::Case::Snake) == want)
+                    .ok_or("Unknown type")
+            }
+        }
+
+        impl ::std::convert::AsRef<str> for #enum_ident {
+            fn as_ref(&self) -> &str {
+                match self {
+                    #( #enum_ident::#variant_idents => #strings, )*
+                }
+            }
+        }
+
+        impl ::serde::Serialize for #enum_ident {
+            fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+            where
+                S: ::serde::Serializer,
+            {
+                serializer.serialize_str(self.as_ref())
+            }
+        }
+
+        impl<'de> ::serde::Deserialize<'de> for #enum_ident {
+            fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+            where
+                D: ::serde::Deserializer<'de>,
+            {
+                let s = <::std::string::String as ::serde::Deserialize>::deserialize(deserializer)?;
+                s.parse().map_err(::serde::de::Error::custom)
+            }
+        }
+
+        /// All types as string slices
+        pub const #const_ident: &[&str] = &[ #( #strings ),* ];
+
+        impl ::dynenum::ClassificationType for #enum_ident {
+            type Err = &'static str;
+            fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+                <Self as ::std::str::FromStr>::from_str(s)
+            }
+            fn all() -> &'static [&'static str] {
+                #const_ident
+            }
+        }
+    }
+}
+
+/// Builds the `matches` body for a single variant from its patterns.
+fn variant_matcher(v: &Variant) -> proc_macro2::TokenStream {
+    let regex = v.regex.as_ref().map(|re| {
+        quote! {{
+            static RE: ::std::sync::OnceLock<::regex::Regex> = ::std::sync::OnceLock::new();
+            RE.get_or_init(|| ::regex::Regex::new(#re).expect("invalid regex in dynenum spec"))
+                .is_match(input)
+        }}
+    });
+    let keywords = (!v.keywords.is_empty()).then(|| {
+        let kw = &v.keywords;
+        quote! { [ #( #kw ),* ].iter().any(|k| input.contains(k)) }
+    });
+    match (regex, keywords) {
+        (Some(r), Some(k)) => quote! { #r || #k },
+        (Some(r), None) => r,
+        (None, Some(k)) => k,
+        (None, None) => quote! { false },
+    }
+}
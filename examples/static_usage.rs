@@ -1,6 +1,6 @@
 //! Example: Using dynenum with static-types feature
 
-use dynenum::{Information, ALL_INFORMATION};
+use dynenum::Information;
 use std::str::FromStr;
 
 /// Example function that takes an Information enum
@@ -10,12 +10,12 @@ fn handle_information(info: Information) {
         Information::PhoneNumber => println!("Action: Handle phone!"),
         Information::Date => println!("Action: Handle date!"),
         Information::Credential => println!("Action: Handle credential!"),
+    }
 }
 
 fn main() {
-    println!("All static types:");
-    for t in ALL_INFORMATION {
-        let info = Information::from_str(t).unwrap();
+    println!("All static types ({} total):", Information::COUNT);
+    for info in Information::iter() {
         println!("Type: {} (variant: {:?})", info, info);
         handle_information(info.clone());
     }
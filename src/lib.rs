@@ -21,8 +21,8 @@
 //! - Types are generated as a real Rust enum at build time from `types.yaml`.
 //! - The enum name is taken from the YAML key (e.g., `Information`).
 //! - Use [`Information`] (an enum), [`ALL_INFORMATION`], and `FromStr`.
-//! - Example:
-//!   ```no_run
+//! - Example (ignored by default, since these symbols need `static-types`):
+//!   ```ignore
 //!   use dynenum::{Information, ALL_INFORMATION};
 //!   use std::str::FromStr;
 //!   for t in ALL_INFORMATION {
@@ -49,55 +49,158 @@ use std::fs::File;
 #[cfg(not(feature = "static-types"))]
 use std::io::BufReader;
 
+// Lets the `dynenum!` macro emit `::dynenum::ClassificationType` impls that
+// resolve both inside this crate and in downstream crates.
+extern crate self as dynenum;
+
+/// A uniform handle over classification types regardless of mode.
+///
+/// Both the dynamic [`Type`] and every enum generated by
+/// [`dynenum!`](dynenum_macros::dynenum) implement this trait, so generic code
+/// — and the [`match_type!`] macro — can be written once against whichever mode
+/// the downstream crate enabled.
+pub trait ClassificationType: AsRef<str> + Sized {
+    /// The error returned by [`from_str`](ClassificationType::from_str).
+    type Err;
+
+    /// The canonical string form of this value.
+    fn as_str(&self) -> &str {
+        self.as_ref()
+    }
+
+    /// Parses a value from its canonical string form.
+    fn from_str(s: &str) -> Result<Self, Self::Err>;
+
+    /// Every known variant as a string slice. Dynamic types, which carry no
+    /// compile-time variant list, return an empty slice.
+    fn all() -> &'static [&'static str];
+}
+
 #[cfg(not(feature = "static-types"))]
 mod dynamic_types {
     use super::*;
+    use regex::Regex;
     use serde::Deserialize;
     /// A type loaded from YAML, behaving like a dynamic enum.
     ///
+    /// The canonical `name` identifies the type; an optional regex pattern and
+    /// keyword list drive [`Type::matches`]. Equality and hashing consider only
+    /// the name, so a `Type` built by hand compares equal to one loaded from
+    /// YAML. The pattern is kept as a `String` (compiled on demand) rather than
+    /// a compiled [`Regex`], so `Type` stays a plain hashable key.
+    ///
     /// Only available when the `static-types` feature is **not** enabled.
-    #[derive(Clone, Eq)]
-    pub struct Type(pub String);
+    #[derive(Clone)]
+    pub struct Type {
+        pub name: String,
+        regex: Option<String>,
+        keywords: Vec<String>,
+    }
+
+    impl Type {
+        /// Creates a bare type with no classification patterns.
+        pub fn new(name: impl Into<String>) -> Self {
+            Type {
+                name: name.into(),
+                regex: None,
+                keywords: Vec::new(),
+            }
+        }
+
+        /// Returns `true` if `input` matches this type's regex or any keyword.
+        /// A type without patterns never matches.
+        ///
+        /// The pattern was already validated when the [`Type`] was loaded, and
+        /// its compiled [`Regex`] is memoized in a process-wide cache keyed by
+        /// the pattern string, so classifying many inputs doesn't recompile.
+        pub fn matches(&self, input: &str) -> bool {
+            self.regex
+                .as_ref()
+                .is_some_and(|re| compiled_regex(re).is_match(input))
+                || self.keywords.iter().any(|k| input.contains(k))
+        }
+    }
+
+    /// Returns the compiled [`Regex`] for `pattern`, caching it for reuse.
+    ///
+    /// [`Regex`] is internally reference-counted, so the returned clone is
+    /// cheap. The pattern is validated when a [`Type`] is loaded, hence the
+    /// `expect`.
+    fn compiled_regex(pattern: &str) -> Regex {
+        use std::sync::{Mutex, OnceLock};
+        static CACHE: OnceLock<Mutex<HashMap<String, Regex>>> = OnceLock::new();
+        let mut cache = CACHE.get_or_init(|| Mutex::new(HashMap::new())).lock().unwrap();
+        if let Some(re) = cache.get(pattern) {
+            return re.clone();
+        }
+        let re = Regex::new(pattern).expect("regex validated when the Type was loaded");
+        cache.insert(pattern.to_string(), re.clone());
+        re
+    }
 
     impl PartialEq for Type {
         fn eq(&self, other: &Self) -> bool {
-            self.0 == other.0
+            self.name == other.name
         }
     }
 
+    impl Eq for Type {}
+
     impl Hash for Type {
         fn hash<H: Hasher>(&self, state: &mut H) {
-            self.0.hash(state)
+            self.name.hash(state)
         }
     }
 
     impl fmt::Debug for Type {
         fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-            write!(f, "Type({:?})", self.0)
+            write!(f, "Type({:?})", self.name)
         }
     }
 
     impl fmt::Display for Type {
         fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-            self.0.fmt(f)
+            self.name.fmt(f)
         }
     }
 
     impl AsRef<str> for Type {
         fn as_ref(&self) -> &str {
-            &self.0
+            &self.name
         }
     }
 
     impl From<&str> for Type {
         fn from(s: &str) -> Self {
-            Type(s.to_string())
+            Type::new(s)
         }
     }
 
     impl From<String> for Type {
         fn from(s: String) -> Self {
-            Type(s)
+            Type::new(s)
+        }
+    }
+
+    impl super::ClassificationType for Type {
+        // Any string is a valid dynamic type, so parsing never fails.
+        type Err = std::convert::Infallible;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            Ok(Type::new(s))
+        }
+
+        fn all() -> &'static [&'static str] {
+            &[]
+        }
+    }
+
+    impl serde::Serialize for Type {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            serializer.serialize_str(&self.name)
         }
     }
 
@@ -107,16 +210,32 @@ mod dynamic_types {
             D: serde::Deserializer<'de>,
         {
             let s = String::deserialize(deserializer)?;
-            Ok(Type(s))
+            Ok(Type::new(s))
         }
     }
 
-    #[derive(Debug, Deserialize)]
-    struct TypesYaml(pub std::collections::BTreeMap<String, Vec<String>>);
+    /// Tries each type in `types` (in iteration order) and returns the first
+    /// whose pattern matches `input`, or `None`.
+    ///
+    /// Pass an ordered collection for declaration-order tie-breaking;
+    /// [`load_types_from_yaml_ordered`] returns a `Vec<Type>` in YAML order for
+    /// exactly this purpose, whereas the [`HashSet`] from
+    /// [`load_types_from_yaml`] has unspecified iteration order.
+    pub fn classify<'a, I>(types: I, input: &str) -> Option<&'a Type>
+    where
+        I: IntoIterator<Item = &'a Type>,
+    {
+        types.into_iter().find(|t| t.matches(input))
+    }
+
     /// Loads types from a YAML file.
     ///
     /// Only available when the `static-types` feature is **not** enabled.
     ///
+    /// Each variant is either a scalar name or a single-key mapping
+    /// `name: {regex: "...", keywords: [...]}`; the patterns are validated and
+    /// stored on the returned [`Type`]s.
+    ///
     /// # Arguments
     /// * `path` - Path to the YAML file.
     ///
@@ -129,25 +248,241 @@ mod dynamic_types {
     /// let types = load_types_from_yaml(std::path::Path::new("types.yaml")).unwrap();
     /// ```
     pub fn load_types_from_yaml(path: &Path) -> Result<HashSet<Type>, Box<dyn std::error::Error>> {
+        Ok(load_types_from_yaml_ordered(path)?.into_iter().collect())
+    }
+
+    /// Loads types from a YAML file, preserving declaration order.
+    ///
+    /// Only available when the `static-types` feature is **not** enabled.
+    ///
+    /// Identical to [`load_types_from_yaml`] but returns a `Vec<Type>` in the
+    /// order the variants appear in the file, so [`classify`] can honour
+    /// declaration-order tie-breaking.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use dynenum::load_types_from_yaml_ordered;
+    /// let types = load_types_from_yaml_ordered(std::path::Path::new("types.yaml")).unwrap();
+    /// ```
+    pub fn load_types_from_yaml_ordered(path: &Path) -> Result<Vec<Type>, Box<dyn std::error::Error>> {
         let file = File::open(path)?;
         let reader = BufReader::new(file);
-        let parsed: TypesYaml = serde_yaml::from_reader(reader)?;
-        let variants = parsed.0.values().next().ok_or("No enum key found in YAML")?;
-        Ok(variants.iter().cloned().map(Type).collect())
+        let doc: serde_yaml::Value = serde_yaml::from_reader(reader)?;
+        let seq = doc
+            .as_mapping()
+            .and_then(|map| map.values().next())
+            .and_then(|v| v.as_sequence())
+            .ok_or("No enum key found in YAML")?;
+        seq.iter().map(parse_type).collect()
+    }
+
+    /// Parses one YAML sequence entry into a [`Type`], validating any regex.
+    fn parse_type(entry: &serde_yaml::Value) -> Result<Type, Box<dyn std::error::Error>> {
+        if let Some(name) = entry.as_str() {
+            return Ok(Type::new(name));
+        }
+        let (key, props) = entry
+            .as_mapping()
+            .and_then(|m| m.iter().next())
+            .ok_or("variant must be a string or a single-key mapping")?;
+        let name = key.as_str().ok_or("variant name must be a string")?.to_string();
+        let regex = match props.get("regex").and_then(|r| r.as_str()) {
+            // Validate the pattern now but store the source string; the compiled
+            // `Regex` must not live inside the hashed key type.
+            Some(re) => {
+                Regex::new(re)?;
+                Some(re.to_string())
+            }
+            None => None,
+        };
+        let keywords = props
+            .get("keywords")
+            .and_then(|k| k.as_sequence())
+            .map(|seq| seq.iter().filter_map(|k| k.as_str().map(str::to_string)).collect())
+            .unwrap_or_default();
+        Ok(Type {
+            name,
+            regex,
+            keywords,
+        })
+    }
+
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+
+    /// The serialized format of a [`TypesBuilder`] file source.
+    ///
+    /// Inferred from the file extension by [`TypesBuilder::add_source`], or set
+    /// explicitly with [`TypesBuilder::add_source_with_format`].
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Format {
+        Yaml,
+        Json,
+        Toml,
+    }
+
+    impl Format {
+        /// Infers the format from a path's extension (`yaml`/`yml`, `json`,
+        /// `toml`), or `None` if the extension is missing or unknown.
+        fn from_path(path: &Path) -> Option<Format> {
+            match path.extension().and_then(|e| e.to_str()) {
+                Some("yaml") | Some("yml") => Some(Format::Yaml),
+                Some("json") => Some(Format::Json),
+                Some("toml") => Some(Format::Toml),
+                _ => None,
+            }
+        }
+    }
+
+    /// A single layer contributing variants to the merged result.
+    ///
+    /// `format` is `None` when [`TypesBuilder::add_source`] could not infer it
+    /// from the extension; the error is surfaced when [`TypesBuilder::build`]
+    /// reaches the source.
+    enum Source {
+        File { path: PathBuf, format: Option<Format> },
+        Env { var: String, key: String },
+    }
+
+    /// Builds a merged set of classification types from layered sources.
+    ///
+    /// Unlike [`load_types_from_yaml`], which reads a single YAML file and keeps
+    /// only its first top-level key, a `TypesBuilder` merges any number of YAML,
+    /// JSON, and TOML files — each a mapping of enum name to a variant list —
+    /// plus additional variants from an environment variable. Sources are
+    /// applied in the order they were added; a later source extends earlier
+    /// variant lists and overrides variants of the same name (so a file later
+    /// in the chain can refine a variant's regex or keywords). The result is
+    /// keyed by enum name, so multi-enum documents are preserved in full.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use dynenum::TypesBuilder;
+    /// let types = TypesBuilder::new()
+    ///     .add_source(std::path::Path::new("types.yaml"))
+    ///     .add_source(std::path::Path::new("overrides.json"))
+    ///     .add_env("DYNENUM_TYPES", "Information")
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    #[derive(Default)]
+    pub struct TypesBuilder {
+        sources: Vec<Source>,
+    }
+
+    impl TypesBuilder {
+        /// Creates an empty builder.
+        pub fn new() -> Self {
+            TypesBuilder::default()
+        }
+
+        /// Adds a file source, inferring its format from the extension.
+        ///
+        /// An unknown or missing extension is reported when [`build`] runs, so
+        /// this method stays chainable.
+        ///
+        /// [`build`]: TypesBuilder::build
+        pub fn add_source(mut self, path: &Path) -> Self {
+            self.sources.push(Source::File {
+                path: path.to_path_buf(),
+                format: Format::from_path(path),
+            });
+            self
+        }
+
+        /// Adds a file source with an explicit format.
+        pub fn add_source_with_format(mut self, path: &Path, format: Format) -> Self {
+            self.sources.push(Source::File {
+                path: path.to_path_buf(),
+                format: Some(format),
+            });
+            self
+        }
+
+        /// Adds variants from a comma-separated environment variable.
+        ///
+        /// At [`build`] time the value of `var` (e.g. `DYNENUM_TYPES=foo,bar`)
+        /// is split on commas and each trimmed, non-empty entry is merged as a
+        /// bare variant under the enum named `key`. A missing variable is a
+        /// no-op.
+        ///
+        /// [`build`]: TypesBuilder::build
+        pub fn add_env(mut self, var: impl Into<String>, key: impl Into<String>) -> Self {
+            self.sources.push(Source::Env {
+                var: var.into(),
+                key: key.into(),
+            });
+            self
+        }
+
+        /// Merges every source into a map of enum name to its variant set.
+        pub fn build(self) -> Result<HashMap<String, HashSet<Type>>, Box<dyn std::error::Error>> {
+            let mut merged: HashMap<String, HashSet<Type>> = HashMap::new();
+            for source in self.sources {
+                match source {
+                    Source::File { path, format } => {
+                        let format = format.ok_or_else(|| {
+                            format!(
+                                "cannot infer format for {}; use add_source_with_format",
+                                path.display()
+                            )
+                        })?;
+                        let text = std::fs::read_to_string(&path)?;
+                        let doc: serde_yaml::Value = match format {
+                            Format::Yaml => serde_yaml::from_str(&text)?,
+                            Format::Json => serde_json::from_str(&text)?,
+                            Format::Toml => toml::from_str(&text)?,
+                        };
+                        let map = doc
+                            .as_mapping()
+                            .ok_or("document must map enum names to variant lists")?;
+                        for (key, value) in map {
+                            let name = key.as_str().ok_or("enum name must be a string")?;
+                            let seq = value
+                                .as_sequence()
+                                .ok_or("enum value must be a sequence of variants")?;
+                            let bucket = merged.entry(name.to_string()).or_default();
+                            for entry in seq {
+                                merge_type(bucket, parse_type(entry)?);
+                            }
+                        }
+                    }
+                    Source::Env { var, key } => {
+                        if let Ok(value) = std::env::var(&var) {
+                            let bucket = merged.entry(key).or_default();
+                            for name in value.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+                                merge_type(bucket, Type::new(name));
+                            }
+                        }
+                    }
+                }
+            }
+            Ok(merged)
+        }
+    }
+
+    /// Inserts `ty`, replacing any existing type with the same name so later
+    /// sources override earlier ones rather than being dropped by the set.
+    fn merge_type(bucket: &mut HashSet<Type>, ty: Type) {
+        bucket.replace(ty);
     }
 }
 
 #[cfg(not(feature = "static-types"))]
-pub use dynamic_types::{Type, load_types_from_yaml};
+pub use dynamic_types::{
+    Type, Format, TypesBuilder, classify, load_types_from_yaml, load_types_from_yaml_ordered,
+};
 
-/// Macro for matching on Type as if it were an enum.
+/// Macro for matching on any [`ClassificationType`] as if it were an enum.
+///
+/// Works uniformly over the dynamic [`Type`] and every generated enum, since
+/// both implement [`ClassificationType`] (and thus `AsRef<str>`).
 ///
-/// # Example (static mode)
+/// # Example
 /// ```rust
-/// use dynenum::{Information, match_type};
-/// use std::str::FromStr;
-/// let info = Information::from_str("email").unwrap();
-/// match_type!(info,
+/// use dynenum::{Type, match_type};
+/// let t = Type::new("email");
+/// match_type!(t,
 ///     "email" => { println!("Email!"); },
 ///     _ => { println!("Other"); }
 /// );
@@ -174,17 +509,78 @@ mod static_types {
     //!
     //! # Static mode
     //!
-    //! When the `static-types` feature is enabled, `Type` is a generated enum.
+    //! When the `static-types` feature is enabled, the enum is expanded inline
+    //! by the [`dynenum!`](dynenum_macros::dynenum) proc-macro from the variant
+    //! list in `types.yaml`.
     //!
-    //! - Use [`ALL_TYPES`] for all possible types.
+    //! - Use `ALL_<NAME>` for all possible types.
     //! - Use `FromStr` to parse from string.
     //! - Use `Display`/`AsRef<str>` to get the string value.
-    include!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/generated_types.rs"));
+    dynenum_macros::dynenum!(include_yaml = "types.yaml");
 }
 
 #[cfg(feature = "static-types")]
 pub use static_types::*;
 
+#[cfg(feature = "static-types")]
+#[cfg(test)]
+mod static_tests {
+    use super::*;
+
+    #[test]
+    fn test_enum_serialize_round_trip() {
+        let json = serde_json::to_string(&Information::Credential).unwrap();
+        assert_eq!(json, "\"credential\"");
+        let back: Information = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, Information::Credential);
+
+        let yaml = serde_yaml::to_string(&Information::PhoneNumber).unwrap();
+        assert_eq!(yaml.trim(), "phone_number");
+    }
+
+    #[test]
+    fn test_from_str_ignore_case() {
+        assert_eq!(
+            Information::from_str_ignore_case("Email").unwrap(),
+            Information::Email
+        );
+        assert_eq!(
+            Information::from_str_ignore_case("PHONE_NUMBER").unwrap(),
+            Information::PhoneNumber
+        );
+        // Exact parsing still rejects off-case input.
+        assert!("Email".parse::<Information>().is_err());
+    }
+
+    #[test]
+    fn test_classification_type_trait() {
+        use super::ClassificationType;
+        assert_eq!(<Information as ClassificationType>::all(), ALL_INFORMATION);
+        let info = <Information as ClassificationType>::from_str("credential").unwrap();
+        assert_eq!(info.as_str(), "credential");
+    }
+
+    #[test]
+    fn test_matches_and_classify() {
+        // regex and keyword matching from the bundled types.yaml patterns
+        assert_eq!(
+            Information::classify("alice@example.com"),
+            Some(Information::Email)
+        );
+        assert_eq!(
+            Information::classify("my api_key leaked"),
+            Some(Information::Credential)
+        );
+        // a canonical ISO date classifies as Date, not PhoneNumber
+        assert_eq!(Information::classify("2024-01-01"), Some(Information::Date));
+        // nothing matches -> None
+        assert_eq!(Information::classify("----"), None);
+
+        assert!(Information::Date.matches("2024-01-01"));
+        assert!(!Information::Date.matches("not a date"));
+    }
+}
+
 #[cfg(not(feature = "static-types"))]
 #[cfg(test)]
 mod tests {
@@ -196,9 +592,9 @@ mod tests {
 
     #[test]
     fn test_type_traits() {
-        let a = Type("email".to_string());
-        let b = Type("email".to_string());
-        let c = Type("phone".to_string());
+        let a = Type::new("email");
+        let b = Type::new("email");
+        let c = Type::new("phone");
         assert_eq!(a, b);
         assert_ne!(a, c);
         let mut set = HashSet::new();
@@ -214,7 +610,44 @@ mod tests {
     fn test_type_deserialize() {
         let yaml = "---\nemail\n";
         let ty: Type = serde_yaml::from_str(yaml).unwrap();
-        assert_eq!(ty, Type("email".to_string()));
+        assert_eq!(ty, Type::new("email"));
+    }
+
+    #[test]
+    fn test_type_serialize_round_trip() {
+        let ty = Type::new("credential");
+        let json = serde_json::to_string(&ty).unwrap();
+        assert_eq!(json, "\"credential\"");
+        let back: Type = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, ty);
+    }
+
+    #[test]
+    fn test_matches_and_classify() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("kinds.yaml");
+        let yaml = r#"Kinds:
+  - email: { regex: '^[^@\s]+@[^@\s]+$' }
+  - date: { regex: '^\d{4}-\d{2}-\d{2}$' }
+  - secret: { keywords: [password, token] }
+  - any_digits: { regex: '\d+' }
+"#;
+        File::create(&path).unwrap().write_all(yaml.as_bytes()).unwrap();
+        let types = load_types_from_yaml_ordered(&path).unwrap();
+
+        let named = |input| classify(&types, input).map(|t| t.name.as_str());
+        // regex matching
+        assert_eq!(named("a@b.com"), Some("email"));
+        // keyword matching
+        assert_eq!(named("my password is secret"), Some("secret"));
+        // nothing matches -> None
+        assert_eq!(named("   "), None);
+        // declaration order breaks ties: both `date` and `any_digits` match.
+        assert_eq!(named("2024-01-01"), Some("date"));
+
+        let date = types.iter().find(|t| t.name == "date").unwrap();
+        assert!(date.matches("2024-01-01"));
+        assert!(!date.matches("not a date"));
     }
 
     #[test]
@@ -226,16 +659,53 @@ mod tests {
         file.write_all(yaml.as_bytes()).unwrap();
         let set = load_types_from_yaml(&file_path).unwrap();
         let expected: HashSet<_> = [
-            Type("email".to_string()),
-            Type("phone".to_string()),
-            Type("date".to_string()),
+            Type::new("email"),
+            Type::new("phone"),
+            Type::new("date"),
         ].into_iter().collect();
         assert_eq!(set, expected);
     }
 
+    #[test]
+    fn test_types_builder_merges_sources_and_env() {
+        let dir = tempdir().unwrap();
+        let yaml_path = dir.path().join("base.yaml");
+        let mut yaml = File::create(&yaml_path).unwrap();
+        yaml.write_all(b"Information:\n  - email\n  - phone\n").unwrap();
+        let json_path = dir.path().join("extra.json");
+        let mut json = File::create(&json_path).unwrap();
+        json.write_all(br#"{"Information": ["date"], "Network": ["ip"]}"#)
+            .unwrap();
+
+        std::env::set_var("DYNENUM_TYPES_TEST", "credential, token");
+        let merged = TypesBuilder::new()
+            .add_source(&yaml_path)
+            .add_source(&json_path)
+            .add_env("DYNENUM_TYPES_TEST", "Information")
+            .build()
+            .unwrap();
+        std::env::remove_var("DYNENUM_TYPES_TEST");
+
+        let information = &merged["Information"];
+        let expected: HashSet<_> = ["email", "phone", "date", "credential", "token"]
+            .into_iter()
+            .map(Type::new)
+            .collect();
+        assert_eq!(information, &expected);
+        assert_eq!(merged["Network"], [Type::new("ip")].into_iter().collect());
+    }
+
+    #[test]
+    fn test_classification_type_trait() {
+        use super::ClassificationType;
+        let t = Type::from_str("email").unwrap();
+        assert_eq!(t.as_str(), "email");
+        assert!(<Type as ClassificationType>::all().is_empty());
+    }
+
     #[test]
     fn test_match_type_macro() {
-        let t = Type("email".to_string());
+        let t = Type::new("email");
         let mut called = false;
         match_type!(t,
             "email" => { called = true; },
@@ -244,7 +714,7 @@ mod tests {
         );
         assert!(called);
 
-        let t = Type("unknown".to_string());
+        let t = Type::new("unknown");
         let mut default_called = false;
         match_type!(t,
             "email" => { /* do nothing */ },